@@ -1,20 +1,120 @@
 use std::fs;
-use std::path::PathBuf;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::env;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use jsonschema::JSONSchema;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tauri::{AppHandle, Manager};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager, State};
 
-/// Get the path to questions.json
-/// In development: writes directly to src/data/questions.json
-/// In production: uses app data directory
-fn get_questions_path(app: &AppHandle) -> Result<PathBuf, String> {
+/// Number of backup snapshots kept by default before older ones are pruned.
+const DEFAULT_MAX_BACKUPS: usize = 10;
+
+/// The empty question bank template used when there is nothing else to
+/// recover from.
+const EMPTY_QUESTIONS_TEMPLATE: &str = r#"{
+        "questions": [],
+        "version": "1.0.0",
+        "lastUpdated": ""
+    }"#;
+
+/// The JSON Schema for the question bank, embedded at compile time so
+/// validation never depends on a file being present at runtime.
+const QUESTIONS_SCHEMA: &str = include_str!("../schemas/questions.schema.json");
+
+/// Lazily-compiled, process-wide instance of `QUESTIONS_SCHEMA`.
+static QUESTIONS_SCHEMA_VALIDATOR: OnceLock<JSONSchema> = OnceLock::new();
+
+fn questions_schema() -> &'static JSONSchema {
+    QUESTIONS_SCHEMA_VALIDATOR.get_or_init(|| {
+        let schema: Value = serde_json::from_str(QUESTIONS_SCHEMA)
+            .expect("embedded questions schema is not valid JSON");
+        JSONSchema::compile(&schema).expect("embedded questions schema is not a valid JSON Schema")
+    })
+}
+
+/// Validate `value` against the question bank schema, returning one
+/// human-readable "<pointer>: <reason>" line per violation.
+///
+/// The schema alone can't express cross-field invariants like "ids are
+/// unique" or "correctIndex is in range for this question's options", so
+/// those are checked separately and merged into the same error list.
+fn validate_questions_value(value: &Value) -> Result<(), Vec<String>> {
+    let mut errors: Vec<String> = Vec::new();
+
+    if let Err(schema_errors) = questions_schema().validate(value) {
+        errors.extend(schema_errors.map(|e| format!("{}: {}", e.instance_path, e)));
+    }
+
+    errors.extend(check_question_invariants(value));
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Check invariants the JSON Schema can't express: unique `id`s across
+/// `questions[]`, and `correctIndex` in range for that question's `options`.
+fn check_question_invariants(value: &Value) -> Vec<String> {
+    let mut errors: Vec<String> = Vec::new();
+
+    let Some(questions) = value.get("questions").and_then(|q| q.as_array()) else {
+        return errors;
+    };
+
+    let mut first_seen_at: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+
+    for (index, question) in questions.iter().enumerate() {
+        if let Some(id) = question.get("id").and_then(|v| v.as_str()) {
+            match first_seen_at.get(id) {
+                Some(&first_index) => {
+                    errors.push(format!(
+                        "/questions/{}/id: duplicate id \"{}\" (first seen at /questions/{}/id)",
+                        index, id, first_index
+                    ));
+                }
+                None => {
+                    first_seen_at.insert(id, index);
+                }
+            }
+        }
+
+        if let (Some(correct_index), Some(options)) = (
+            question.get("correctIndex").and_then(|v| v.as_i64()),
+            question.get("options").and_then(|v| v.as_array()),
+        ) {
+            if correct_index < 0 || correct_index as usize >= options.len() {
+                errors.push(format!(
+                    "/questions/{}/correctIndex: correctIndex {} is out of bounds for {} option(s)",
+                    index,
+                    correct_index,
+                    options.len()
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
+/// Resolve the path to a named app data file.
+/// If src/data/<file_name> already exists (relative to the current dir or
+/// its parent), that path is used; otherwise the file goes in the app data
+/// directory.
+fn resolve_data_path(app: &AppHandle, file_name: &str) -> Result<PathBuf, String> {
     // Check if we're in development mode by looking for src/data relative to current dir
     // or by checking the TAURI_DEV environment variable
     let current_dir = env::current_dir()
         .map_err(|e| format!("Failed to get current dir: {}", e))?;
 
-    // Try to find src/data/questions.json relative to current directory
-    let dev_path = current_dir.join("src").join("data").join("questions.json");
+    // Try to find src/data/<file_name> relative to current directory
+    let dev_path = current_dir.join("src").join("data").join(file_name);
 
     if dev_path.exists() {
         // Development mode - write directly to source
@@ -23,7 +123,7 @@ fn get_questions_path(app: &AppHandle) -> Result<PathBuf, String> {
 
     // Also check parent directory (in case running from src-tauri)
     let parent_dev_path = current_dir.parent()
-        .map(|p| p.join("src").join("data").join("questions.json"));
+        .map(|p| p.join("src").join("data").join(file_name));
 
     if let Some(path) = parent_dev_path {
         if path.exists() {
@@ -40,7 +140,235 @@ fn get_questions_path(app: &AppHandle) -> Result<PathBuf, String> {
             .map_err(|e| format!("Failed to create app data dir: {}", e))?;
     }
 
-    Ok(app_data_dir.join("questions.json"))
+    Ok(app_data_dir.join(file_name))
+}
+
+/// Get the path to questions.json
+/// In development: writes directly to src/data/questions.json
+/// In production: uses app data directory
+fn get_questions_path(app: &AppHandle) -> Result<PathBuf, String> {
+    resolve_data_path(app, "questions.json")
+}
+
+/// Get the path to settings.json
+/// Uses src/data/settings.json only if that file already exists;
+/// otherwise uses the app data directory
+fn get_settings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    resolve_data_path(app, "settings.json")
+}
+
+/// The `backups/` subdirectory that sits next to `questions.json`.
+fn get_backups_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let backups_dir = get_questions_path(app)?
+        .parent()
+        .ok_or_else(|| "questions.json path has no parent directory".to_string())?
+        .join("backups");
+
+    if !backups_dir.exists() {
+        fs::create_dir_all(&backups_dir)
+            .map_err(|e| format!("Failed to create backups dir: {}", e))?;
+    }
+
+    Ok(backups_dir)
+}
+
+/// First 8 hex characters of the SHA-256 digest of `bytes`, used as a
+/// short, collision-resistant suffix for backup filenames.
+fn short_hash(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    format!("{:x}", digest)[..8].to_string()
+}
+
+/// Seconds since the Unix epoch, used to timestamp backup filenames.
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// A single entry returned by `list_backups`.
+#[derive(Serialize)]
+struct BackupInfo {
+    id: String,
+    timestamp: u64,
+    hash: String,
+}
+
+/// The result of `read_questions`: the (possibly recovered) content, plus
+/// whether recovery kicked in and a human-readable explanation if so.
+#[derive(Serialize)]
+struct ReadQuestionsResult {
+    content: String,
+    recovered: bool,
+    note: Option<String>,
+}
+
+/// Copy the current `questions.json` (if any) into `backups/`, named with
+/// a timestamp and a short content hash, then prune down to `keep` entries.
+fn backup_current_file(questions_path: &Path, backups_dir: &Path, keep: usize) -> Result<(), String> {
+    if !questions_path.exists() {
+        return Ok(());
+    }
+
+    let contents = fs::read(questions_path)
+        .map_err(|e| format!("Failed to read existing questions.json for backup: {}", e))?;
+
+    let timestamp = current_timestamp();
+    let hash = short_hash(&contents);
+    let backup_path = backups_dir.join(format!("questions-{}-{}.json", timestamp, hash));
+
+    fs::write(&backup_path, &contents)
+        .map_err(|e| format!("Failed to write backup: {}", e))?;
+
+    prune_backups(backups_dir, keep)
+}
+
+/// Keep only the `keep` most recent backups in `backups_dir`, removing the rest.
+fn prune_backups(backups_dir: &Path, keep: usize) -> Result<(), String> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(backups_dir)
+        .map_err(|e| format!("Failed to read backups dir: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+        .collect();
+
+    // Filenames are `questions-<timestamp>-<hash>.json`, so lexical order
+    // matches chronological order.
+    entries.sort();
+
+    if entries.len() > keep {
+        for old in &entries[..entries.len() - keep] {
+            fs::remove_file(old).map_err(|e| format!("Failed to prune backup {:?}: {}", old, e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write `contents` to `path` atomically: write to a sibling `.tmp` file,
+/// flush and fsync it, then rename it over the target (atomic on the same
+/// filesystem). This avoids ever leaving `path` truncated or corrupted.
+fn atomic_write(path: &Path, contents: &str) -> Result<(), String> {
+    let tmp_path = path.with_extension("json.tmp");
+
+    let mut tmp_file =
+        File::create(&tmp_path).map_err(|e| format!("Failed to create temp file: {}", e))?;
+    tmp_file
+        .write_all(contents.as_bytes())
+        .map_err(|e| format!("Failed to write temp file: {}", e))?;
+    tmp_file
+        .sync_all()
+        .map_err(|e| format!("Failed to sync temp file: {}", e))?;
+    drop(tmp_file);
+
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to rename temp file into place: {}", e))
+}
+
+/// User-facing preferences, persisted to `settings.json`.
+///
+/// `#[serde(default)]` on every known field means a settings file written
+/// by an older version of the app (missing newer fields) still
+/// deserializes cleanly, and `extra` captures any fields a *newer*
+/// version added so they survive a round trip through an older build.
+#[derive(Serialize, Deserialize)]
+struct AppSettings {
+    #[serde(default)]
+    theme: String,
+    #[serde(default)]
+    last_opened_category: String,
+    #[serde(default = "default_quiz_timer_seconds")]
+    quiz_timer_seconds: u32,
+    #[serde(default = "default_true")]
+    shuffle_questions: bool,
+    /// Fields from a newer app version that this build doesn't know
+    /// about yet. Captured (rather than dropped) so round-tripping
+    /// through an older build never loses them.
+    #[serde(flatten)]
+    extra: serde_json::Map<String, Value>,
+}
+
+fn default_quiz_timer_seconds() -> u32 {
+    30
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        AppSettings {
+            theme: "system".to_string(),
+            last_opened_category: String::new(),
+            quiz_timer_seconds: default_quiz_timer_seconds(),
+            shuffle_questions: default_true(),
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+/// The embedded default permission set, used when no `permissions.json`
+/// is present or it fails to parse.
+const DEFAULT_PERMISSIONS: &str = include_str!("../permissions/default.json");
+
+/// A capability/ACL layer over the invoke handlers: each named permission
+/// (`questions:read`, `questions:write`, `settings:write`, ...) gates one
+/// class of operation. Held in Tauri managed state so it can be flipped
+/// at runtime, e.g. by `set_readonly_mode`.
+#[derive(Clone, Serialize, Deserialize)]
+struct AppPermissions {
+    #[serde(default)]
+    allowed: std::collections::HashMap<String, bool>,
+}
+
+impl Default for AppPermissions {
+    fn default() -> Self {
+        serde_json::from_str(DEFAULT_PERMISSIONS)
+            .expect("embedded default permissions are not valid JSON")
+    }
+}
+
+/// The permissions every build knows about and enforces. A `permissions.json`
+/// that omits one of these (truncated, hand-edited, or from an older
+/// version) must deny it rather than silently grant full access.
+const CORE_PERMISSIONS: &[&str] = &["questions:read", "questions:write", "settings:write"];
+
+impl AppPermissions {
+    /// Core permissions are deny-by-default when missing from the set, so
+    /// a truncated `permissions.json` fails closed instead of open. Only
+    /// names outside `CORE_PERMISSIONS` — i.e. genuinely unknown, presumably
+    /// future permissions this build doesn't enforce yet — default to allowed.
+    fn is_allowed(&self, permission: &str) -> bool {
+        match self.allowed.get(permission) {
+            Some(&allowed) => allowed,
+            None => !CORE_PERMISSIONS.contains(&permission),
+        }
+    }
+}
+
+/// Load `permissions.json` from the path `resolve_data_path` picks for it,
+/// falling back to the embedded default if it is missing or unparseable.
+fn load_permissions(app: &AppHandle) -> AppPermissions {
+    resolve_data_path(app, "permissions.json")
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Check that `permission` is currently allowed, returning a clear
+/// "permission denied: <permission>" error otherwise.
+fn check_permission(permissions: &State<Mutex<AppPermissions>>, permission: &str) -> Result<(), String> {
+    let permissions = permissions
+        .lock()
+        .map_err(|_| "permission state is poisoned".to_string())?;
+
+    if permissions.is_allowed(permission) {
+        Ok(())
+    } else {
+        Err(format!("permission denied: {}", permission))
+    }
 }
 
 /// Initialize questions.json if needed (only for production mode)
@@ -65,50 +393,395 @@ fn init_questions(app: &AppHandle) -> Result<(), String> {
     }
 
     // Fallback: Create empty questions structure
-    let empty_questions = r#"{
-        "questions": [],
-        "version": "1.0.0",
-        "lastUpdated": ""
-    }"#;
-
-    fs::write(&questions_path, empty_questions)
+    fs::write(&questions_path, EMPTY_QUESTIONS_TEMPLATE)
         .map_err(|e| format!("Failed to create empty questions file: {}", e))?;
 
     Ok(())
 }
 
+/// Toggle exam/locked mode at runtime by flipping `questions:write` on or
+/// off in the active permission set, without touching `permissions.json`.
+#[tauri::command]
+fn set_readonly_mode(enabled: bool, permissions: State<Mutex<AppPermissions>>) -> Result<String, String> {
+    let mut permissions = permissions
+        .lock()
+        .map_err(|_| "permission state is poisoned".to_string())?;
+
+    permissions.allowed.insert("questions:write".to_string(), !enabled);
+
+    Ok(if enabled {
+        "Read-only mode enabled: questions:write is now denied".to_string()
+    } else {
+        "Read-only mode disabled: questions:write is now allowed".to_string()
+    })
+}
+
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
 #[tauri::command]
-fn save_questions(app: AppHandle, questions_json: String) -> Result<String, String> {
+fn save_questions(
+    app: AppHandle,
+    questions_json: String,
+    permissions: State<Mutex<AppPermissions>>,
+) -> Result<String, String> {
+    check_permission(&permissions, "questions:write")?;
+
     let questions_path = get_questions_path(&app)?;
 
     // Parse and validate JSON
     let parsed: Value = serde_json::from_str(&questions_json)
         .map_err(|e| format!("Invalid JSON: {}", e))?;
 
+    // Reject anything that doesn't match the question bank schema before
+    // it ever touches disk.
+    validate_questions_value(&parsed)
+        .map_err(|violations| format!("Schema validation failed:\n{}", violations.join("\n")))?;
+
     // Write to file with pretty formatting
     let pretty_json = serde_json::to_string_pretty(&parsed)
         .map_err(|e| format!("Failed to format JSON: {}", e))?;
 
-    fs::write(&questions_path, pretty_json)
-        .map_err(|e| format!("Failed to write file: {}", e))?;
+    // Snapshot the current file into backups/ before we touch it, then
+    // write the new content atomically so a crash mid-write can't corrupt
+    // or truncate the only copy.
+    let backups_dir = get_backups_dir(&app)?;
+    backup_current_file(&questions_path, &backups_dir, DEFAULT_MAX_BACKUPS)?;
+    atomic_write(&questions_path, &pretty_json)?;
 
     Ok(format!("Questions saved successfully to {:?}", questions_path))
 }
 
+/// Restore `questions.json` from a previously saved backup.
+#[tauri::command]
+fn restore_questions(
+    app: AppHandle,
+    backup_id: String,
+    permissions: State<Mutex<AppPermissions>>,
+) -> Result<String, String> {
+    check_permission(&permissions, "questions:write")?;
+
+    let questions_path = get_questions_path(&app)?;
+    let backups_dir = get_backups_dir(&app)?;
+    let backup_path = backups_dir.join(format!("questions-{}.json", backup_id));
+
+    if !backup_path.exists() {
+        return Err(format!("No backup found with id {:?}", backup_id));
+    }
+
+    let contents = fs::read_to_string(&backup_path)
+        .map_err(|e| format!("Failed to read backup {:?}: {}", backup_path, e))?;
+
+    // Back up whatever is currently live before overwriting it, so
+    // restoring is itself undoable.
+    backup_current_file(&questions_path, &backups_dir, DEFAULT_MAX_BACKUPS)?;
+    atomic_write(&questions_path, &contents)?;
+
+    Ok(format!("Restored questions.json from backup {:?}", backup_id))
+}
+
+/// List the available backup snapshots, newest first.
 #[tauri::command]
-fn read_questions(app: AppHandle) -> Result<String, String> {
+fn list_backups(app: AppHandle) -> Result<Vec<BackupInfo>, String> {
+    let backups_dir = get_backups_dir(&app)?;
+
+    let mut backups: Vec<BackupInfo> = fs::read_dir(&backups_dir)
+        .map_err(|e| format!("Failed to read backups dir: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_str()?;
+            let stripped = file_name
+                .strip_prefix("questions-")?
+                .strip_suffix(".json")?;
+            let (timestamp, hash) = stripped.split_once('-')?;
+            Some(BackupInfo {
+                id: stripped.to_string(),
+                timestamp: timestamp.parse().ok()?,
+                hash: hash.to_string(),
+            })
+        })
+        .collect();
+
+    backups.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    Ok(backups)
+}
+
+/// Validate a question bank document against the schema without writing
+/// it anywhere, so the UI can check as the user types.
+#[tauri::command]
+fn validate_questions(json: String) -> Result<String, String> {
+    let parsed: Value = serde_json::from_str(&json).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    match validate_questions_value(&parsed) {
+        Ok(()) => Ok("valid".to_string()),
+        Err(violations) => Err(format!("Schema validation failed:\n{}", violations.join("\n"))),
+    }
+}
+
+#[tauri::command]
+fn read_questions(
+    app: AppHandle,
+    permissions: State<Mutex<AppPermissions>>,
+) -> Result<ReadQuestionsResult, String> {
+    check_permission(&permissions, "questions:read")?;
+
     // Ensure questions file exists
     init_questions(&app)?;
 
     let questions_path = get_questions_path(&app)?;
 
-    fs::read_to_string(&questions_path)
-        .map_err(|e| format!("Failed to read file: {}", e))
+    // Non-UTF8 bytes shouldn't be a hard failure: decode as leniently as
+    // possible and let the JSON parser be the real judge of validity.
+    let bytes = fs::read(&questions_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let contents = String::from_utf8_lossy(&bytes).into_owned();
+
+    if serde_json::from_str::<Value>(&contents).is_ok() {
+        return Ok(ReadQuestionsResult {
+            content: contents,
+            recovered: false,
+            note: None,
+        });
+    }
+
+    recover_corrupt_questions(&app, &questions_path, &contents)
+}
+
+/// `questions.json` failed to parse: move it aside and fall back to the
+/// newest backup, then the bundled resource copy, then the empty
+/// template, so the app always has something usable to open.
+fn recover_corrupt_questions(
+    app: &AppHandle,
+    questions_path: &Path,
+    original_contents: &str,
+) -> Result<ReadQuestionsResult, String> {
+    let parse_error = serde_json::from_str::<Value>(original_contents)
+        .err()
+        .map(|e| e.to_string())
+        .unwrap_or_default();
+
+    let corrupt_path =
+        questions_path.with_file_name(format!("questions.corrupt-{}.json", current_timestamp()));
+    fs::rename(questions_path, &corrupt_path)
+        .map_err(|e| format!("Failed to move corrupt questions file aside: {}", e))?;
+
+    // Walk the candidates in priority order and accept the first one that
+    // actually parses — an unparseable backup must fall through to the
+    // next source instead of being written back as "recovered".
+    let (recovered_contents, source_description) = recovery_candidates(app)
+        .into_iter()
+        .find(|(_, contents)| serde_json::from_str::<Value>(contents).is_ok())
+        .unwrap_or_else(|| (EMPTY_QUESTIONS_TEMPLATE.to_string(), "empty template".to_string()));
+
+    atomic_write(questions_path, &recovered_contents)?;
+
+    let note = format!(
+        "questions.json was corrupted ({}). The damaged file was moved to {:?} and the question bank was recovered from the {}.",
+        parse_error, corrupt_path, source_description
+    );
+
+    Ok(ReadQuestionsResult {
+        content: recovered_contents,
+        recovered: true,
+        note: Some(note),
+    })
+}
+
+/// Recovery candidates in priority order: every `backups/` snapshot
+/// (newest first), then the bundled resource copy, then the empty
+/// template. The caller validates each before accepting it.
+fn recovery_candidates(app: &AppHandle) -> Vec<(String, String)> {
+    let mut candidates: Vec<(String, String)> = all_backup_contents_newest_first(app)
+        .into_iter()
+        .map(|(label, contents)| (contents, format!("backup {}", label)))
+        .collect();
+
+    if let Some(contents) = bundled_resource_questions(app) {
+        candidates.push((contents, "bundled resource copy".to_string()));
+    }
+
+    candidates.push((EMPTY_QUESTIONS_TEMPLATE.to_string(), "empty template".to_string()));
+
+    candidates
+}
+
+/// Read every `backups/` snapshot, newest first.
+fn all_backup_contents_newest_first(app: &AppHandle) -> Vec<(String, String)> {
+    let Ok(backups_dir) = get_backups_dir(app) else {
+        return Vec::new();
+    };
+
+    let Ok(read_dir) = fs::read_dir(&backups_dir) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+        .collect();
+
+    // Filenames are `questions-<timestamp>-<hash>.json`, so lexical order
+    // matches chronological order.
+    entries.sort();
+    entries.reverse();
+
+    entries
+        .into_iter()
+        .filter_map(|path| {
+            let contents = fs::read_to_string(&path).ok()?;
+            let label = path.file_name()?.to_str()?.to_string();
+            Some((label, contents))
+        })
+        .collect()
+}
+
+/// Read the bundled resource copy of `questions.json`, if present.
+fn bundled_resource_questions(app: &AppHandle) -> Option<String> {
+    let resource_path = app.path().resource_dir().ok()?.join("data").join("questions.json");
+    fs::read_to_string(&resource_path).ok()
+}
+
+/// Expand a source into the `.json` files it refers to: a plain file is
+/// returned as-is, a directory is globbed for its direct `*.json` children.
+fn expand_source(source: &str) -> Result<Vec<PathBuf>, String> {
+    let path = Path::new(source);
+
+    if path.is_dir() {
+        let mut files: Vec<PathBuf> = fs::read_dir(path)
+            .map_err(|e| format!("Failed to read source dir {:?}: {}", path, e))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|p| p.extension().map(|ext| ext == "json").unwrap_or(false))
+            .collect();
+        files.sort();
+        Ok(files)
+    } else {
+        Ok(vec![path.to_path_buf()])
+    }
+}
+
+/// Read a question-bank JSON document, returning its `questions` array.
+fn read_questions_array(path: &Path) -> Result<Vec<Value>, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+    let parsed: Value =
+        serde_json::from_str(&contents).map_err(|e| format!("Invalid JSON in {:?}: {}", path, e))?;
+
+    Ok(parsed
+        .get("questions")
+        .and_then(|q| q.as_array())
+        .cloned()
+        .unwrap_or_default())
+}
+
+/// Merge the `questions` arrays of several source files/directories into a
+/// single document, de-duplicating by question `id` (later sources win)
+/// and recording which source each surviving question came from.
+#[tauri::command]
+fn read_merged_questions(
+    sources: Vec<String>,
+    permissions: State<Mutex<AppPermissions>>,
+) -> Result<String, String> {
+    check_permission(&permissions, "questions:read")?;
+
+    let mut order: Vec<String> = Vec::new();
+    let mut questions_by_id: std::collections::HashMap<String, Value> =
+        std::collections::HashMap::new();
+    let mut provenance: std::collections::HashMap<String, String> =
+        std::collections::HashMap::new();
+
+    for source in &sources {
+        for file in expand_source(source)? {
+            let source_label = file.to_string_lossy().to_string();
+
+            for question in read_questions_array(&file)? {
+                let id = question
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| format!("Question in {:?} is missing an \"id\"", file))?
+                    .to_string();
+
+                if !questions_by_id.contains_key(&id) {
+                    order.push(id.clone());
+                }
+
+                questions_by_id.insert(id.clone(), question);
+                provenance.insert(id, source_label.clone());
+            }
+        }
+    }
+
+    let merged_questions: Vec<Value> = order
+        .iter()
+        .map(|id| questions_by_id.get(id).cloned().unwrap_or(Value::Null))
+        .collect();
+
+    let merged = serde_json::json!({
+        "questions": merged_questions,
+        "version": "1.0.0",
+        "lastUpdated": current_timestamp().to_string(),
+        "provenance": provenance,
+    });
+
+    serde_json::to_string_pretty(&merged).map_err(|e| format!("Failed to format merged JSON: {}", e))
+}
+
+/// Read app settings, writing out and returning the defaults if the
+/// settings file is missing or fails to parse.
+#[tauri::command]
+fn read_settings(app: AppHandle) -> Result<String, String> {
+    let settings_path = get_settings_path(&app)?;
+
+    let existing = fs::read_to_string(&settings_path).ok();
+    let parses = existing
+        .as_ref()
+        .is_some_and(|contents| serde_json::from_str::<AppSettings>(contents).is_ok());
+
+    // Only missing/unparseable files get (re)written with defaults — a
+    // settings file that already parses is returned untouched, so fields
+    // this build doesn't know about survive the round trip.
+    if let (Some(contents), true) = (&existing, parses) {
+        return Ok(contents.clone());
+    }
+
+    let pretty_json = serde_json::to_string_pretty(&AppSettings::default())
+        .map_err(|e| format!("Failed to format settings: {}", e))?;
+
+    fs::write(&settings_path, &pretty_json)
+        .map_err(|e| format!("Failed to write settings file: {}", e))?;
+
+    Ok(pretty_json)
+}
+
+/// Validate and persist app settings.
+#[tauri::command]
+fn save_settings(
+    app: AppHandle,
+    settings_json: String,
+    permissions: State<Mutex<AppPermissions>>,
+) -> Result<String, String> {
+    check_permission(&permissions, "settings:write")?;
+
+    let settings_path = get_settings_path(&app)?;
+
+    // Deserializing into AppSettings (rather than just checking it's valid
+    // JSON) is the validation: unknown fields are kept in `extra` and
+    // written back, and missing fields fall back to their defaults via
+    // #[serde(default)].
+    let settings: AppSettings = serde_json::from_str(&settings_json)
+        .map_err(|e| format!("Invalid settings: {}", e))?;
+
+    let pretty_json = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("Failed to format settings: {}", e))?;
+
+    fs::write(&settings_path, pretty_json)
+        .map_err(|e| format!("Failed to write settings file: {}", e))?;
+
+    Ok(format!("Settings saved successfully to {:?}", settings_path))
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -122,9 +795,24 @@ pub fn run() {
             if let Err(e) = init_questions(app.handle()) {
                 eprintln!("Warning: Failed to initialize questions: {}", e);
             }
+
+            let permissions = load_permissions(app.handle());
+            app.manage(Mutex::new(permissions));
+
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![greet, save_questions, read_questions])
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            save_questions,
+            read_questions,
+            validate_questions,
+            restore_questions,
+            list_backups,
+            read_settings,
+            save_settings,
+            read_merged_questions,
+            set_readonly_mode
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }